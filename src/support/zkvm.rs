@@ -8,10 +8,31 @@
 /// Currently these functions are specified to support only 256 bit [Uint]'s and
 /// take pointers to their limbs as arguments. Providing other sizes
 /// will result in an undefined behavior.
-use core::{cmp::Ordering, mem::MaybeUninit};
+///
+/// `Uint<512, 8>` is additionally accelerated, but not via its own
+/// intrinsics: `add`/`sub`/`cmp` are instead composed from a pair of chained
+/// 256-bit intrinsic calls over the low and high halves. Every other width
+/// falls back to the generic implementation below.
+///
+/// The `extern "C"` block below is only linked in on `target_os = "zkvm"`,
+/// where the host is expected to provide these symbols. Every operation in
+/// this module therefore has two bodies: a `#[cfg(target_os = "zkvm")]`
+/// block that defers to the intrinsic for `Uint<256, 4>` (and, for
+/// `add`/`sub`/`cmp`, to the composed path for `Uint<512, 8>`), and a
+/// plain-Rust fallback used both for every other width and for ordinary
+/// (non-zkvm) builds, so `cargo test` on the host keeps working.
+use core::cmp::Ordering;
+// `MaybeUninit` is only referenced from the `#[cfg(target_os = "zkvm")]`
+// intrinsic fast paths below; gate the import the same way so host
+// (non-zkvm) builds don't trip over an unused import. `Ordering`, by
+// contrast, is also used by the unconditional `Ord for Uint` impl further
+// down, so it stays ungated.
+#[cfg(target_os = "zkvm")]
+use core::mem::MaybeUninit;
 
 use crate::Uint;
 
+#[cfg(target_os = "zkvm")]
 extern "C" {
     /// Add two 256-bit numbers and store in `result`.
     pub fn wrapping_add_impl(result: *mut u8, a: *const u8, b: *const u8);
@@ -37,13 +58,92 @@ extern "C" {
     pub fn cmp_impl(a: *const u8, b: *const u8) -> Ordering;
     /// Clone a 256-bit number into `result`. `zero` has to
     pub fn clone_impl(result: *mut u8, a: *const u8, zero: *const u8);
+    /// Add two 256-bit numbers modulo `m` and store in `result`.
+    ///
+    /// The host is expected to compute `(a + b) % m` exactly, i.e. the
+    /// addition must not wrap at 256 bits before the reduction is applied.
+    /// `addmod(a, b, 0)` must yield `0`, matching the EVM `ADDMOD` opcode.
+    pub fn addmod_impl(result: *mut u8, a: *const u8, b: *const u8, m: *const u8);
+    /// Subtract two 256-bit numbers modulo `m` and store in `result`.
+    ///
+    /// The host is expected to compute `(a - b) % m` using a non-negative
+    /// representative, i.e. as if `a` and `b` were first reduced mod `m` and
+    /// the subtraction wrapped around `m` on underflow. `submod(a, b, 0)`
+    /// must yield `0`.
+    pub fn submod_impl(result: *mut u8, a: *const u8, b: *const u8, m: *const u8);
+    /// Multiply two 256-bit numbers modulo `m` and store in `result`.
+    ///
+    /// The host is expected to compute the full 512-bit product `a * b`
+    /// before reducing modulo `m`; the result must match EVM `MULMOD`, which
+    /// means it is **not** equivalent to reducing the wrapped 256-bit product
+    /// `wrapping_mul_impl(a, b) % m`. `mulmod(a, b, 0)` must yield `0`.
+    pub fn mulmod_impl(result: *mut u8, a: *const u8, b: *const u8, m: *const u8);
+    /// Add two 256-bit numbers, store the wrapped result in `result`, and
+    /// return whether the addition overflowed (carry-out of the top bit).
+    pub fn overflowing_add_impl(result: *mut u8, a: *const u8, b: *const u8) -> bool;
+    /// Subtract two 256-bit numbers, store the wrapped result in `result`,
+    /// and return whether the subtraction underflowed (borrow out of the top
+    /// bit).
+    pub fn overflowing_sub_impl(result: *mut u8, a: *const u8, b: *const u8) -> bool;
+    /// Multiply two 256-bit numbers and store the full 512-bit product
+    /// across `lo` and `hi`, such that `hi * 2**256 + lo == a * b`.
+    pub fn mul_wide_impl(lo: *mut u8, hi: *mut u8, a: *const u8, b: *const u8);
+    /// Divide `a` by `b`, storing the quotient in `quotient` and the
+    /// remainder in `remainder`.
+    ///
+    /// The host must guarantee `a == quotient * b + remainder` with
+    /// `remainder < b`. Callers only invoke this for nonzero `b`; dividing by
+    /// zero is UB, consistent with the rest of this module's contract.
+    pub fn divrem_impl(quotient: *mut u8, remainder: *mut u8, a: *const u8, b: *const u8);
+    /// Count the number of `1` bits in `a`.
+    pub fn count_ones_impl(a: *const u8) -> u32;
+    /// Count the number of leading zero bits in `a`, i.e. starting from bit
+    /// 255. Returns `256` if `a` is zero.
+    pub fn leading_zeros_impl(a: *const u8) -> u32;
+    /// Count the number of trailing zero bits in `a`, i.e. starting from bit
+    /// 0. Returns `256` if `a` is zero.
+    pub fn trailing_zeros_impl(a: *const u8) -> u32;
+    /// Return the value of bit `index` of `a`. `index >= 256` returns
+    /// `false` rather than being UB.
+    pub fn bit_impl(a: *const u8, index: usize) -> bool;
+    /// Branchlessly select between `a` and `b` and store the result in
+    /// `result`: `a` when `choice == 0`, `b` when `choice == 1`. Any other
+    /// value of `choice` is UB, as with other sizes in this module.
+    pub fn select_impl(result: *mut u8, a: *const u8, b: *const u8, choice: u32);
+    /// Branchlessly, conditionally swap `x` and `y` in place: a no-op when
+    /// `choice == 0`, a swap when `choice == 1`. Any other value of `choice`
+    /// is UB.
+    pub fn cswap_impl(x: *mut u8, y: *mut u8, choice: u32);
+}
+
+/// Selects, at const-eval time, whether a given [`Uint`] shape is backed by
+/// the zkvm intrinsics declared above.
+///
+/// Every call site below checks `Self::IS_NATIVE` instead of repeating the
+/// `BITS == 256` comparison inline; the comparison lives here once. Every
+/// call site is itself `#[cfg(target_os = "zkvm")]`-gated, so off-target
+/// this trait has no consumers and must be gated too, or it's dead code
+/// under `-D warnings`.
+#[cfg(target_os = "zkvm")]
+trait NativeU256 {
+    const IS_NATIVE: bool;
+    /// `true` for `Uint<512, 8>`, which doesn't get its own intrinsics but
+    /// instead composes two chained calls into the 256-bit ones above.
+    const IS_COMPOSED_512: bool;
+}
+
+#[cfg(target_os = "zkvm")]
+impl<const BITS: usize, const LIMBS: usize> NativeU256 for Uint<BITS, LIMBS> {
+    const IS_NATIVE: bool = BITS == 256;
+    const IS_COMPOSED_512: bool = BITS == 512;
 }
 
 impl<const BITS: usize, const LIMBS: usize> Copy for Uint<BITS, LIMBS> {}
 
 impl<const BITS: usize, const LIMBS: usize> Clone for Uint<BITS, LIMBS> {
     fn clone(&self) -> Self {
-        if BITS == 256 {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
             let mut uninit: MaybeUninit<Self> = MaybeUninit::uninit();
             unsafe {
                 clone_impl(
@@ -60,17 +160,763 @@ impl<const BITS: usize, const LIMBS: usize> Clone for Uint<BITS, LIMBS> {
 
 impl<const BITS: usize, const LIMBS: usize> PartialEq for Uint<BITS, LIMBS> {
     fn eq(&self, other: &Self) -> bool {
-        if BITS == 256 {
-            unsafe {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            return unsafe {
                 eq_impl(
                     self.limbs.as_ptr() as *const u8,
                     other.limbs.as_ptr() as *const u8,
                 )
+            };
+        }
+        self.limbs == other.limbs
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Eq for Uint<BITS, LIMBS> {}
+
+impl<const BITS: usize, const LIMBS: usize> PartialOrd for Uint<BITS, LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Ord for Uint<BITS, LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        #[cfg(target_os = "zkvm")]
+        if let Some(ordering) = self.cmp_accelerated(other) {
+            return ordering;
+        }
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Accelerated fast path for [`Ord::cmp`] above: `Some` when this shape
+    /// is backed by the native 256-bit intrinsic or the composed 512-bit
+    /// path, `None` when `cmp` should fall back to the generic limb-wise
+    /// comparison.
+    #[cfg(target_os = "zkvm")]
+    fn cmp_accelerated(&self, other: &Self) -> Option<Ordering> {
+        if Self::IS_NATIVE {
+            return Some(unsafe {
+                cmp_impl(
+                    self.limbs.as_ptr() as *const u8,
+                    other.limbs.as_ptr() as *const u8,
+                )
+            });
+        }
+        if Self::IS_COMPOSED_512 {
+            // Compare the high 256-bit half first, since it dominates the
+            // ordering; only fall through to the low half on a tie.
+            let high = unsafe {
+                cmp_impl(
+                    self.limbs[4..8].as_ptr() as *const u8,
+                    other.limbs[4..8].as_ptr() as *const u8,
+                )
+            };
+            return Some(if high != Ordering::Equal {
+                high
+            } else {
+                unsafe {
+                    cmp_impl(
+                        self.limbs[0..4].as_ptr() as *const u8,
+                        other.limbs[0..4].as_ptr() as *const u8,
+                    )
+                }
+            });
+        }
+        None
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Compute `(self + rhs) % modulus`, matching the EVM `ADDMOD` opcode.
+    ///
+    /// The addition is performed without wrapping at `BITS` bits before the
+    /// reduction, so the result is correct even when `self + rhs` would
+    /// overflow `Self`. Returns `0` if `modulus` is `0`.
+    #[must_use]
+    pub fn add_mod(self, rhs: Self, modulus: Self) -> Self {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            let mut uninit: MaybeUninit<Self> = MaybeUninit::uninit();
+            unsafe {
+                addmod_impl(
+                    (*uninit.as_mut_ptr()).limbs.as_mut_ptr() as *mut u8,
+                    self.limbs.as_ptr() as *const u8,
+                    rhs.limbs.as_ptr() as *const u8,
+                    modulus.limbs.as_ptr() as *const u8,
+                );
+                return uninit.assume_init();
+            }
+        }
+        if modulus.is_zero() {
+            return Self::ZERO;
+        }
+        let a = self % modulus;
+        let b = rhs % modulus;
+        let (wrapped, overflow) = a.overflowing_add(b);
+        if overflow || wrapped >= modulus {
+            wrapped.wrapping_sub(modulus)
+        } else {
+            wrapped
+        }
+    }
+
+    /// Compute `(self - rhs) % modulus`, wrapping the difference around
+    /// `modulus` on underflow rather than panicking or returning a negative
+    /// value. The EVM has no dedicated `SUBMOD` opcode; these are the
+    /// semantics a contract gets by computing `ADDMOD(a, MOD_NEG(b), m)`.
+    ///
+    /// Returns `0` if `modulus` is `0`.
+    #[must_use]
+    pub fn sub_mod(self, rhs: Self, modulus: Self) -> Self {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            let mut uninit: MaybeUninit<Self> = MaybeUninit::uninit();
+            unsafe {
+                submod_impl(
+                    (*uninit.as_mut_ptr()).limbs.as_mut_ptr() as *mut u8,
+                    self.limbs.as_ptr() as *const u8,
+                    rhs.limbs.as_ptr() as *const u8,
+                    modulus.limbs.as_ptr() as *const u8,
+                );
+                return uninit.assume_init();
             }
+        }
+        if modulus.is_zero() {
+            return Self::ZERO;
+        }
+        let lhs = self % modulus;
+        let rhs = rhs % modulus;
+        if lhs >= rhs {
+            lhs - rhs
         } else {
-            self.limbs == other.limbs
+            modulus - (rhs - lhs)
+        }
+    }
+
+    /// Compute `(self * rhs) % modulus`, matching the EVM `MULMOD` opcode.
+    ///
+    /// The full double-width product `self * rhs` is computed before the
+    /// reduction, so the result is correct even when the product would
+    /// overflow `Self`. Returns `0` if `modulus` is `0`.
+    #[must_use]
+    pub fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            let mut uninit: MaybeUninit<Self> = MaybeUninit::uninit();
+            unsafe {
+                mulmod_impl(
+                    (*uninit.as_mut_ptr()).limbs.as_mut_ptr() as *mut u8,
+                    self.limbs.as_ptr() as *const u8,
+                    rhs.limbs.as_ptr() as *const u8,
+                    modulus.limbs.as_ptr() as *const u8,
+                );
+                return uninit.assume_init();
+            }
+        }
+        if modulus.is_zero() {
+            return Self::ZERO;
+        }
+        // Double-and-add modular multiplication: avoids needing a
+        // double-width intermediate type, at the cost of `BITS` iterations.
+        let mut a = self % modulus;
+        let mut b = rhs;
+        let mut result = Self::ZERO;
+        while b != Self::ZERO {
+            if b & Self::from(1) == Self::from(1) {
+                result = result.add_mod(a, modulus);
+            }
+            a = a.add_mod(a, modulus);
+            b >>= 1;
+        }
+        result
+    }
+
+    /// Add `self` and `rhs`, returning the wrapped result along with whether
+    /// the addition overflowed (carry-out of the top bit).
+    #[must_use]
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            let mut uninit: MaybeUninit<Self> = MaybeUninit::uninit();
+            unsafe {
+                let overflow = overflowing_add_impl(
+                    (*uninit.as_mut_ptr()).limbs.as_mut_ptr() as *mut u8,
+                    self.limbs.as_ptr() as *const u8,
+                    rhs.limbs.as_ptr() as *const u8,
+                );
+                return (uninit.assume_init(), overflow);
+            }
+        }
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_COMPOSED_512 {
+            return Self::overflowing_add_composed_512(self, rhs);
+        }
+        let mut result = self;
+        let mut carry = false;
+        for i in 0..LIMBS {
+            let (sum, c1) = result.limbs[i].overflowing_add(rhs.limbs[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            result.limbs[i] = sum;
+            carry = c1 || c2;
+        }
+        // `BITS` need not be a multiple of 64, so the top limb can carry more
+        // bits than are actually significant: a carry-out of bit `BITS - 1`
+        // doesn't always surface as a 64-bit carry out of the top limb. Mask
+        // the top limb down to its `BITS`-significant bits and fold any bits
+        // that would otherwise overflow `Self` into the overflow flag.
+        let top = LIMBS - 1;
+        let valid_bits_in_top = (BITS - 64 * top) as u32;
+        let top_mask = if valid_bits_in_top >= u64::BITS {
+            u64::MAX
+        } else {
+            (1u64 << valid_bits_in_top) - 1
+        };
+        let overflow = carry || result.limbs[top] & !top_mask != 0;
+        result.limbs[top] &= top_mask;
+        (result, overflow)
+    }
+
+    /// `overflowing_add` for `Uint<512, 8>`, composed from two chained
+    /// 256-bit `overflowing_add_impl` calls with carry propagation between
+    /// the low and high halves.
+    #[cfg(target_os = "zkvm")]
+    fn overflowing_add_composed_512(self, rhs: Self) -> (Self, bool) {
+        let mut result = self;
+        let carry_lo = unsafe {
+            overflowing_add_impl(
+                result.limbs[0..4].as_mut_ptr() as *mut u8,
+                self.limbs[0..4].as_ptr() as *const u8,
+                rhs.limbs[0..4].as_ptr() as *const u8,
+            )
+        };
+        let mut carry_hi = unsafe {
+            overflowing_add_impl(
+                result.limbs[4..8].as_mut_ptr() as *mut u8,
+                self.limbs[4..8].as_ptr() as *const u8,
+                rhs.limbs[4..8].as_ptr() as *const u8,
+            )
+        };
+        if carry_lo {
+            let one: [u8; 32] = {
+                let mut bytes = [0u8; 32];
+                bytes[0] = 1;
+                bytes
+            };
+            let mut high = [0u8; 32];
+            let carry_from_one = unsafe {
+                overflowing_add_impl(
+                    high.as_mut_ptr(),
+                    result.limbs[4..8].as_ptr() as *const u8,
+                    one.as_ptr(),
+                )
+            };
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    high.as_ptr(),
+                    result.limbs[4..8].as_mut_ptr() as *mut u8,
+                    32,
+                );
+            }
+            carry_hi |= carry_from_one;
+        }
+        (result, carry_hi)
+    }
+
+    /// Subtract `rhs` from `self`, returning the wrapped result along with
+    /// whether the subtraction underflowed (borrow out of the top bit).
+    #[must_use]
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            let mut uninit: MaybeUninit<Self> = MaybeUninit::uninit();
+            unsafe {
+                let overflow = overflowing_sub_impl(
+                    (*uninit.as_mut_ptr()).limbs.as_mut_ptr() as *mut u8,
+                    self.limbs.as_ptr() as *const u8,
+                    rhs.limbs.as_ptr() as *const u8,
+                );
+                return (uninit.assume_init(), overflow);
+            }
+        }
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_COMPOSED_512 {
+            return Self::overflowing_sub_composed_512(self, rhs);
+        }
+        let mut result = self;
+        let mut borrow = false;
+        for i in 0..LIMBS {
+            let (diff, b1) = result.limbs[i].overflowing_sub(rhs.limbs[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            result.limbs[i] = diff;
+            borrow = b1 || b2;
+        }
+        // As in `overflowing_add`, the top limb can hold more than `BITS -
+        // 64 * (LIMBS - 1)` significant bits when `BITS` isn't a multiple of
+        // 64; a borrow that ripples through the unused high bits of the top
+        // limb must not leak into the wrapped result.
+        let top = LIMBS - 1;
+        let valid_bits_in_top = (BITS - 64 * top) as u32;
+        let top_mask = if valid_bits_in_top >= u64::BITS {
+            u64::MAX
+        } else {
+            (1u64 << valid_bits_in_top) - 1
+        };
+        result.limbs[top] &= top_mask;
+        (result, borrow)
+    }
+
+    /// `overflowing_sub` for `Uint<512, 8>`, composed from two chained
+    /// 256-bit `overflowing_sub_impl` calls with borrow propagation between
+    /// the low and high halves.
+    #[cfg(target_os = "zkvm")]
+    fn overflowing_sub_composed_512(self, rhs: Self) -> (Self, bool) {
+        let mut result = self;
+        let borrow_lo = unsafe {
+            overflowing_sub_impl(
+                result.limbs[0..4].as_mut_ptr() as *mut u8,
+                self.limbs[0..4].as_ptr() as *const u8,
+                rhs.limbs[0..4].as_ptr() as *const u8,
+            )
+        };
+        let mut borrow_hi = unsafe {
+            overflowing_sub_impl(
+                result.limbs[4..8].as_mut_ptr() as *mut u8,
+                self.limbs[4..8].as_ptr() as *const u8,
+                rhs.limbs[4..8].as_ptr() as *const u8,
+            )
+        };
+        if borrow_lo {
+            let one: [u8; 32] = {
+                let mut bytes = [0u8; 32];
+                bytes[0] = 1;
+                bytes
+            };
+            let mut high = [0u8; 32];
+            let borrow_from_one = unsafe {
+                overflowing_sub_impl(
+                    high.as_mut_ptr(),
+                    result.limbs[4..8].as_ptr() as *const u8,
+                    one.as_ptr(),
+                )
+            };
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    high.as_ptr(),
+                    result.limbs[4..8].as_mut_ptr() as *mut u8,
+                    32,
+                );
+            }
+            borrow_hi |= borrow_from_one;
+        }
+        (result, borrow_hi)
+    }
+
+    /// Add `self` and `rhs`, returning `None` if the addition overflowed.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (result, false) => Some(result),
+            (_, true) => None,
+        }
+    }
+
+    /// Subtract `rhs` from `self`, returning `None` if the subtraction
+    /// underflowed.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (result, false) => Some(result),
+            (_, true) => None,
+        }
+    }
+
+    /// Multiply `self` and `rhs`, returning the product as a [`Uint`] of the
+    /// requested output size. The full product is kept when `LIMBS_RES >=
+    /// 2 * LIMBS`; for a narrower output only the low `LIMBS_RES` limbs of
+    /// the product are returned.
+    #[must_use]
+    pub fn widening_mul<const BITS_RES: usize, const LIMBS_RES: usize>(
+        self,
+        rhs: Self,
+    ) -> Uint<BITS_RES, LIMBS_RES> {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE && BITS_RES == 512 {
+            let mut lo = [0u8; 32];
+            let mut hi = [0u8; 32];
+            let mut uninit: MaybeUninit<Uint<BITS_RES, LIMBS_RES>> = MaybeUninit::uninit();
+            unsafe {
+                mul_wide_impl(
+                    lo.as_mut_ptr(),
+                    hi.as_mut_ptr(),
+                    self.limbs.as_ptr() as *const u8,
+                    rhs.limbs.as_ptr() as *const u8,
+                );
+                let limbs = (*uninit.as_mut_ptr()).limbs.as_mut_ptr() as *mut u8;
+                core::ptr::copy_nonoverlapping(lo.as_ptr(), limbs, 32);
+                core::ptr::copy_nonoverlapping(hi.as_ptr(), limbs.add(32), 32);
+                return uninit.assume_init();
+            }
+        }
+        // Schoolbook long multiplication, accumulating directly into the
+        // output limbs so callers can pick any output width without needing
+        // a `2 * LIMBS`-sized intermediate (not expressible in stable Rust).
+        let mut limbs = [0u64; LIMBS_RES];
+        for i in 0..LIMBS {
+            let mut carry = 0u64;
+            let mut k = i;
+            for j in 0..LIMBS {
+                k = i + j;
+                if k >= LIMBS_RES {
+                    break;
+                }
+                let acc =
+                    limbs[k] as u128 + (self.limbs[i] as u128) * (rhs.limbs[j] as u128) + carry as u128;
+                limbs[k] = acc as u64;
+                carry = (acc >> 64) as u64;
+            }
+            k += 1;
+            while carry != 0 && k < LIMBS_RES {
+                let acc = limbs[k] as u128 + carry as u128;
+                limbs[k] = acc as u64;
+                carry = (acc >> 64) as u64;
+                k += 1;
+            }
+        }
+        Uint { limbs }
+    }
+
+    /// Divide `self` by `rhs`, returning `(quotient, remainder)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[must_use]
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "division by zero");
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            let mut quotient: MaybeUninit<Self> = MaybeUninit::uninit();
+            let mut remainder: MaybeUninit<Self> = MaybeUninit::uninit();
+            unsafe {
+                divrem_impl(
+                    (*quotient.as_mut_ptr()).limbs.as_mut_ptr() as *mut u8,
+                    (*remainder.as_mut_ptr()).limbs.as_mut_ptr() as *mut u8,
+                    self.limbs.as_ptr() as *const u8,
+                    rhs.limbs.as_ptr() as *const u8,
+                );
+                return (quotient.assume_init(), remainder.assume_init());
+            }
+        }
+        // Generic long-division: shifts one bit of `self` into the remainder
+        // and tests a subtraction of `rhs` per step.
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+        for i in (0..BITS).rev() {
+            remainder <<= 1;
+            if self.bit(i) {
+                remainder |= Self::from(1);
+            }
+            if remainder >= rhs {
+                remainder -= rhs;
+                quotient |= Self::from(1) << i;
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// Divide `self` by `rhs`, returning `None` if `rhs` is zero.
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(self.div_rem(rhs).0)
+        }
+    }
+
+    /// Compute `self % rhs`, returning `None` if `rhs` is zero.
+    #[must_use]
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(self.div_rem(rhs).1)
+        }
+    }
+
+    /// Count the number of `1` bits.
+    #[must_use]
+    pub fn count_ones(self) -> u32 {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            return unsafe { count_ones_impl(self.limbs.as_ptr() as *const u8) };
+        }
+        self.limbs.iter().map(|limb| limb.count_ones()).sum()
+    }
+
+    /// Count the number of leading zero bits, i.e. starting from the most
+    /// significant bit. Returns `BITS` if `self` is zero.
+    #[must_use]
+    pub fn leading_zeros(self) -> u32 {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            return unsafe { leading_zeros_impl(self.limbs.as_ptr() as *const u8) };
+        }
+        // The top limb only holds `valid_bits_in_top` significant bits when
+        // `BITS` isn't a multiple of 64; its remaining high bits are always
+        // zero and must not be counted as leading zeros of `self`.
+        let top = LIMBS - 1;
+        let valid_bits_in_top = (BITS - 64 * top) as u32;
+        let mut zeros = 0;
+        for (i, &limb) in self.limbs.iter().enumerate().rev() {
+            if limb == 0 {
+                zeros += if i == top { valid_bits_in_top } else { u64::BITS };
+            } else if i == top {
+                zeros += limb.leading_zeros() - (u64::BITS - valid_bits_in_top);
+                break;
+            } else {
+                zeros += limb.leading_zeros();
+                break;
+            }
+        }
+        zeros
+    }
+
+    /// Count the number of trailing zero bits, i.e. starting from the least
+    /// significant bit. Returns `BITS` if `self` is zero.
+    #[must_use]
+    pub fn trailing_zeros(self) -> u32 {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            return unsafe { trailing_zeros_impl(self.limbs.as_ptr() as *const u8) };
+        }
+        // When the top limb is zero, only its `valid_bits_in_top` bits
+        // actually exist in `self`; counting a full 64 there would overcount
+        // for widths that aren't a multiple of 64 (e.g. the zero value).
+        let top = LIMBS - 1;
+        let valid_bits_in_top = (BITS - 64 * top) as u32;
+        let mut zeros = 0;
+        for (i, &limb) in self.limbs.iter().enumerate() {
+            if limb == 0 {
+                zeros += if i == top { valid_bits_in_top } else { u64::BITS };
+            } else {
+                zeros += limb.trailing_zeros();
+                break;
+            }
+        }
+        zeros
+    }
+
+    /// Return the value of bit `index`, counting from the least significant
+    /// bit. `index >= BITS` returns `false`.
+    #[must_use]
+    pub fn bit(self, index: usize) -> bool {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            return unsafe { bit_impl(self.limbs.as_ptr() as *const u8, index) };
+        }
+        if index >= BITS {
+            return false;
+        }
+        (self.limbs[index / 64] >> (index % 64)) & 1 == 1
+    }
+
+    /// The number of bits required to represent `self`, i.e. `0` for `0`
+    /// and `BITS - self.leading_zeros()` otherwise.
+    #[must_use]
+    pub fn bit_len(self) -> usize {
+        (BITS as u32 - self.leading_zeros()) as usize
+    }
+
+    /// Select `a` or `b` without a data-dependent branch: `a` when
+    /// `choice == 0`, `b` when `choice == 1`.
+    ///
+    /// # Safety-adjacent note
+    ///
+    /// Passing a `choice` other than `0` or `1` is UB on the accelerated
+    /// path, not a panic; the fallback masks on `choice != 0` instead. A
+    /// `debug_assert!` catches misuse in debug builds, but release builds
+    /// rely on the caller upholding the `choice <= 1` contract.
+    #[must_use]
+    pub fn conditional_select(a: &Self, b: &Self, choice: u32) -> Self {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            debug_assert!(choice <= 1, "choice must be 0 or 1");
+            let mut uninit: MaybeUninit<Self> = MaybeUninit::uninit();
+            unsafe {
+                select_impl(
+                    (*uninit.as_mut_ptr()).limbs.as_mut_ptr() as *mut u8,
+                    a.limbs.as_ptr() as *const u8,
+                    b.limbs.as_ptr() as *const u8,
+                    choice,
+                );
+                return uninit.assume_init();
+            }
+        }
+        let mask = 0u64.wrapping_sub((choice != 0) as u64);
+        let mut result = *a;
+        for i in 0..LIMBS {
+            result.limbs[i] = (a.limbs[i] & !mask) | (b.limbs[i] & mask);
+        }
+        result
+    }
+
+    /// Conditionally swap `a` and `b` in place without a data-dependent
+    /// branch: a no-op when `choice == 0`, a swap when `choice == 1`.
+    ///
+    /// # Safety-adjacent note
+    ///
+    /// Passing a `choice` other than `0` or `1` is UB on the accelerated
+    /// path, not a panic; the fallback masks on `choice != 0` instead. A
+    /// `debug_assert!` catches misuse in debug builds, but release builds
+    /// rely on the caller upholding the `choice <= 1` contract.
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: u32) {
+        #[cfg(target_os = "zkvm")]
+        if Self::IS_NATIVE {
+            debug_assert!(choice <= 1, "choice must be 0 or 1");
+            unsafe {
+                cswap_impl(
+                    a.limbs.as_mut_ptr() as *mut u8,
+                    b.limbs.as_mut_ptr() as *mut u8,
+                    choice,
+                );
+            }
+            return;
+        }
+        let mask = 0u64.wrapping_sub((choice != 0) as u64);
+        for i in 0..LIMBS {
+            let t = (a.limbs[i] ^ b.limbs[i]) & mask;
+            a.limbs[i] ^= t;
+            b.limbs[i] ^= t;
         }
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> Eq for Uint<BITS, LIMBS> {}
+#[cfg(feature = "subtle")]
+impl<const BITS: usize, const LIMBS: usize> subtle::ConditionallySelectable for Uint<BITS, LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Self::conditional_select(a, b, choice.unwrap_u8() as u32)
+    }
+}
+
+#[cfg(test)]
+mod overflowing_tests {
+    use super::Uint;
+
+    // `BITS = 200` isn't a multiple of 64, so the top limb (index 3) only
+    // has 8 significant bits; this is exactly the shape that previously let
+    // overflow/underflow silently escape past `BITS` without a 64-bit carry.
+    type U200 = Uint<200, 4>;
+
+    fn u200(limbs: [u64; 4]) -> U200 {
+        U200 { limbs }
+    }
+
+    #[test]
+    fn overflowing_add_no_overflow_below_bits() {
+        let a = u200([0, 0, 0, 0x10]);
+        let b = u200([1, 0, 0, 0]);
+        let (result, overflow) = a.overflowing_add(b);
+        assert!(!overflow);
+        assert_eq!(result.limbs, [1, 0, 0, 0x10]);
+    }
+
+    #[test]
+    fn overflowing_add_detects_overflow_within_the_top_limb() {
+        // Each operand's top limb only uses its 8 significant bits; their
+        // sum crosses `2^200` without ever producing a 64-bit carry out of
+        // the top limb.
+        let a = u200([0, 0, 0, 0x80]);
+        let b = u200([0, 0, 0, 0x80]);
+        let (result, overflow) = a.overflowing_add(b);
+        assert!(overflow);
+        assert_eq!(result.limbs, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn overflowing_add_wraps_at_max_value() {
+        let max = u200([u64::MAX, u64::MAX, u64::MAX, 0xFF]);
+        let one = u200([1, 0, 0, 0]);
+        let (result, overflow) = max.overflowing_add(one);
+        assert!(overflow);
+        assert_eq!(result.limbs, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn overflowing_sub_no_underflow() {
+        let a = u200([1, 0, 0, 0x10]);
+        let b = u200([1, 0, 0, 0]);
+        let (result, underflow) = a.overflowing_sub(b);
+        assert!(!underflow);
+        assert_eq!(result.limbs, [0, 0, 0, 0x10]);
+    }
+
+    #[test]
+    fn overflowing_sub_detects_underflow_and_masks_the_top_limb() {
+        let zero = u200([0, 0, 0, 0]);
+        let one = u200([1, 0, 0, 0]);
+        let (result, underflow) = zero.overflowing_sub(one);
+        assert!(underflow);
+        // `0 - 1` wraps to `2^200 - 1`, not `2^256 - 1`.
+        assert_eq!(result.limbs, [u64::MAX, u64::MAX, u64::MAX, 0xFF]);
+    }
+}
+
+#[cfg(test)]
+mod bit_scan_tests {
+    use super::Uint;
+
+    // Same non-64-multiple shape as `overflowing_tests`: the top limb
+    // (index 3) only has 8 significant bits out of its 64.
+    type U200 = Uint<200, 4>;
+
+    fn u200(limbs: [u64; 4]) -> U200 {
+        U200 { limbs }
+    }
+
+    #[test]
+    fn leading_zeros_of_zero_is_bits() {
+        assert_eq!(u200([0, 0, 0, 0]).leading_zeros(), 200);
+    }
+
+    #[test]
+    fn trailing_zeros_of_zero_is_bits() {
+        assert_eq!(u200([0, 0, 0, 0]).trailing_zeros(), 200);
+    }
+
+    #[test]
+    fn leading_zeros_accounts_for_the_partial_top_limb() {
+        // Bit 192 is the lowest bit of the top limb, which only has 8
+        // significant bits for `BITS = 200`; the real leading-zero count is
+        // 7, not `limb.leading_zeros() == 63`.
+        let value = u200([0, 0, 0, 1]);
+        assert_eq!(value.leading_zeros(), 7);
+    }
+
+    #[test]
+    fn trailing_zeros_of_the_same_value() {
+        let value = u200([0, 0, 0, 1]);
+        assert_eq!(value.trailing_zeros(), 192);
+    }
+
+    #[test]
+    fn leading_and_trailing_zeros_of_one() {
+        let value = u200([1, 0, 0, 0]);
+        assert_eq!(value.leading_zeros(), 199);
+        assert_eq!(value.trailing_zeros(), 0);
+    }
+
+    #[test]
+    fn bit_len_of_zero_is_zero() {
+        assert_eq!(u200([0, 0, 0, 0]).bit_len(), 0);
+    }
+
+    #[test]
+    fn bit_len_of_the_top_bit() {
+        assert_eq!(u200([0, 0, 0, 1]).bit_len(), 193);
+    }
+}